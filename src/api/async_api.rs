@@ -0,0 +1,131 @@
+//! An async counterpart to `API`, for use inside a Tokio runtime.
+//!
+//! Enabled with the `async` feature. Shares `base_url`, `Envelope` and
+//! `parse_response` with the blocking `API` so the two front-ends' base url,
+//! request bodies and `Response` deserialization can't diverge.
+
+use reqwest::Client;
+use serde::Serialize;
+
+use crate::api::{parse_response, Envelope};
+use crate::schema;
+use crate::{base_url, AsanaError};
+
+type AccessToken = String;
+
+/// The async equivalent of `API`, built on `reqwest::Client` instead of
+/// `reqwest::blocking::Client`. Authenticates with a Personal Access Token.
+pub struct AsyncAPI {
+    client: Client,
+    pat: AccessToken,
+    url: String,
+}
+
+impl AsyncAPI {
+    /// Creates a new AsyncAPI struct from the given token
+    pub fn from_token<S: AsRef<str>>(token: S) -> Self {
+        AsyncAPI {
+            client: Client::new(),
+            pat: String::from(token.as_ref()),
+            url: base_url(),
+        }
+    }
+
+    /// Returns the token provided when the AsyncAPI struct was created
+    pub fn token(&self) -> &str {
+        &self.pat
+    }
+
+    /// Adds a url segment to the url
+    pub fn request<S: AsRef<str>>(&mut self, url: S) -> &mut Self {
+        self.url += url.as_ref();
+        self
+    }
+
+    /// Executes the request
+    pub async fn get(&mut self) -> Result<schema::Response, AsanaError> {
+        let resp = self.client
+            .get(&self.url)
+            .bearer_auth(&self.pat)
+            .send()
+            .await?;
+
+        self.finish(resp).await
+    }
+
+    /// Creates a resource by POSTing `body`, wrapped in the `{ "data": ... }`
+    /// envelope Asana expects, to the current url.
+    pub async fn post<B: Serialize>(&mut self, body: &B) -> Result<schema::Response, AsanaError> {
+        let resp = self.client
+            .post(&self.url)
+            .bearer_auth(&self.pat)
+            .json(&Envelope { data: body })
+            .send()
+            .await?;
+
+        self.finish(resp).await
+    }
+
+    /// Updates a resource by PUTting `body`, wrapped in the `{ "data": ... }`
+    /// envelope Asana expects, to the current url.
+    pub async fn put<B: Serialize>(&mut self, body: &B) -> Result<schema::Response, AsanaError> {
+        let resp = self.client
+            .put(&self.url)
+            .bearer_auth(&self.pat)
+            .json(&Envelope { data: body })
+            .send()
+            .await?;
+
+        self.finish(resp).await
+    }
+
+    /// Deletes the resource at the current url.
+    pub async fn delete(&mut self) -> Result<schema::Response, AsanaError> {
+        let resp = self.client
+            .delete(&self.url)
+            .bearer_auth(&self.pat)
+            .send()
+            .await?;
+
+        self.finish(resp).await
+    }
+
+    /// Resets `self.url` and turns a raw HTTP response into a
+    /// `schema::Response`, or the appropriate `AsanaError` if Asana
+    /// rejected or rate limited the request.
+    async fn finish(&mut self, resp: reqwest::Response) -> Result<schema::Response, AsanaError> {
+        // Reset the url
+        self.url = base_url();
+
+        let status = resp.status().as_u16();
+        let retry_after = resp.headers()
+            .get("Retry-After")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
+
+        let text = resp.text().await?;
+        parse_response(status, retry_after, &text)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_pat;
+
+    #[test]
+    fn new_async_api_with_token() {
+        let api = AsyncAPI::from_token("my token");
+        assert_eq!(api.token(), "my token");
+    }
+
+    #[tokio::test]
+    async fn test_get_me() {
+        let mut asana = AsyncAPI::from_token(get_pat());
+        let resp = asana.request("/users/me").get().await.expect("Couldn't perform request");
+        let user = resp.value::<schema::User>();
+        assert!(user.is_some());
+        assert_eq!(user.unwrap().resource_type, "user");
+    }
+}
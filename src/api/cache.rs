@@ -0,0 +1,74 @@
+//! An in-memory, time-to-live cache of `API::get` responses, keyed by the
+//! fully-built request url.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::schema;
+
+pub(crate) struct Cache {
+    ttl: Duration,
+    entries: HashMap<String, (Instant, schema::Response)>,
+}
+
+impl Cache {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Cache {
+            ttl,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Returns a clone of the cached response for `url`, if one exists and
+    /// hasn't outlived the cache's ttl.
+    pub(crate) fn get(&self, url: &str) -> Option<schema::Response> {
+        let (inserted_at, response) = self.entries.get(url)?;
+
+        if inserted_at.elapsed() < self.ttl {
+            Some(response.clone())
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn insert(&mut self, url: String, response: schema::Response) {
+        self.entries.insert(url, (Instant::now(), response));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response() -> schema::Response {
+        serde_json::from_str(r#"{ "data": { "gid": "1", "resource_type": "user" } }"#).unwrap()
+    }
+
+    #[test]
+    fn miss_on_empty_cache() {
+        let cache = Cache::new(Duration::from_secs(60));
+        assert!(cache.get("/users/me").is_none());
+    }
+
+    #[test]
+    fn hit_within_ttl() {
+        let mut cache = Cache::new(Duration::from_secs(60));
+        cache.insert(String::from("/users/me"), response());
+        assert!(cache.get("/users/me").is_some());
+    }
+
+    #[test]
+    fn miss_after_ttl_expires() {
+        let mut cache = Cache::new(Duration::from_millis(10));
+        cache.insert(String::from("/users/me"), response());
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(cache.get("/users/me").is_none());
+    }
+
+    #[test]
+    fn miss_for_different_url() {
+        let mut cache = Cache::new(Duration::from_secs(60));
+        cache.insert(String::from("/users/me"), response());
+        assert!(cache.get("/users/other").is_none());
+    }
+}
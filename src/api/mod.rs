@@ -1,23 +1,76 @@
-/// Interact with the Asana API
+//! Interact with the Asana API
 
 mod users;
+mod oauth;
+mod paginator;
+mod cache;
+#[cfg(feature = "async")]
+mod async_api;
 
-use reqwest::blocking::{Client};
+pub use oauth::{AccessToken, OAuthClient};
+pub use paginator::Paginator;
+#[cfg(feature = "async")]
+pub use async_api::AsyncAPI;
+
+use cache::Cache;
+
+use std::time::Duration;
+
+use reqwest::blocking::{Client, Response as HttpResponse};
 use serde::de::DeserializeOwned;
+use serde::Serialize;
 
 use crate::schema;
-use crate::BASE_URL;
+use crate::{base_url, AsanaError};
+
+/// Wraps a request body in the `{ "data": { ... } }` envelope Asana expects
+/// for writes, mirroring how `schema::Response` unwraps the `data` key on
+/// the way back.
+#[derive(Serialize)]
+pub(crate) struct Envelope<'a, B: Serialize> {
+    pub(crate) data: &'a B,
+}
+
+/// Turns a response status and body into a `schema::Response`, or the
+/// appropriate `AsanaError` if Asana rejected or rate limited the request.
+/// Shared by the blocking `API` and the `AsyncAPI` so the two front-ends'
+/// response handling can't drift apart.
+pub(crate) fn parse_response(status: u16, retry_after: Option<u64>, text: &str) -> Result<schema::Response, AsanaError> {
+    if status == 429 {
+        return Err(AsanaError::RateLimited { retry_after: Duration::from_secs(retry_after.unwrap_or(0)) });
+    }
+
+    if !(200..300).contains(&status) {
+        let errors = serde_json::from_str::<schema::Errors>(text)?;
+        return Err(AsanaError::Api { status, errors: errors.errors });
+    }
+
+    Ok(serde_json::from_str::<schema::Response>(text)?)
+}
+
+/// How close to expiry an OAuth access token has to be before `API::get`
+/// will refresh it automatically.
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
 
-type AccessToken = String;
+/// The credential an `API` authenticates its requests with.
+enum Auth {
+    /// A long-lived Personal Access Token.
+    Token(String),
+    /// An OAuth2 access token, refreshed through its `OAuthClient` as needed.
+    OAuth(OAuthClient, AccessToken),
+}
 
 /// This handles interactions with the Asana API.
 ///
-/// It posts payloads to the API and returns the result. It also handles
-/// authentication through a Personal Access Token (PAT)
+/// It posts payloads to the API and returns the result. It authenticates
+/// either through a Personal Access Token (PAT, see `from_token`) or through
+/// OAuth2 (see `from_oauth`), in which case it transparently refreshes the
+/// access token once it's close to expiring.
 pub struct API {
     client: Client,
-    pat: AccessToken,
-    url: String
+    auth: Auth,
+    url: String,
+    cache: Option<Cache>,
 }
 
 impl API {
@@ -25,14 +78,38 @@ impl API {
     pub fn from_token<S: AsRef<str>>(token: S) -> Self {
         API {
             client: Client::new(),
-            pat: String::from(token.as_ref()),
-            url: String::from(BASE_URL)
+            auth: Auth::Token(String::from(token.as_ref())),
+            url: base_url(),
+            cache: None,
+        }
+    }
+
+    /// Creates a new API struct authenticated through OAuth2. `oauth` is
+    /// the app's registered client, and `token` is the `AccessToken`
+    /// obtained from `oauth.exchange_code()`.
+    pub fn from_oauth(oauth: OAuthClient, token: AccessToken) -> Self {
+        API {
+            client: Client::new(),
+            auth: Auth::OAuth(oauth, token),
+            url: base_url(),
+            cache: None,
         }
     }
 
-    /// Returns the token provided when the API struct was created
+    /// Opts this API into caching `get()` responses in memory, keyed by the
+    /// fully-built request url, for up to `ttl` before re-fetching. Does not
+    /// affect `post`/`put`/`delete`.
+    pub fn with_cache(mut self, ttl: Duration) -> Self {
+        self.cache = Some(Cache::new(ttl));
+        self
+    }
+
+    /// Returns the access token currently used to authenticate requests
     pub fn token(&self) -> &str {
-        &self.pat
+        match &self.auth {
+            Auth::Token(pat) => pat,
+            Auth::OAuth(_, token) => &token.access_token,
+        }
     }
 
     /// Adds a url segment to the url
@@ -41,19 +118,111 @@ impl API {
         self
     }
 
+    /// Refreshes the OAuth access token if it's within `REFRESH_MARGIN` of
+    /// expiring. No-op when authenticated with a Personal Access Token, or
+    /// when there's no refresh token to exchange (the following request is
+    /// left to fail on its own if the token has actually expired).
+    fn refresh_if_needed(&mut self) -> Result<(), AsanaError> {
+        if let Auth::OAuth(oauth, token) = &mut self.auth {
+            if token.is_expiring(REFRESH_MARGIN) {
+                if let Some(refresh_token) = token.refresh_token.clone() {
+                    *token = oauth.refresh(refresh_token)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Executes the request
-    pub fn get(&mut self) -> Result<schema::Response, Box<dyn std::error::Error>> {
+    pub fn get(&mut self) -> Result<schema::Response, AsanaError> {
+        self.refresh_if_needed()?;
+
+        if let Some(cached) = self.cache.as_ref().and_then(|cache| cache.get(&self.url)) {
+            self.url = base_url();
+            return Ok(cached);
+        }
+
+        let url = self.url.clone();
         let resp = self.client
             .get(&self.url)
-            .bearer_auth(&self.pat)
+            .bearer_auth(self.token())
             .send()?;
 
-        let text = resp.text()?;
-        let resp = serde_json::from_str::<schema::Response>(&text)?;
+        let resp = self.finish(resp)?;
+
+        if let Some(cache) = &mut self.cache {
+            cache.insert(url, resp.clone());
+        }
+
+        Ok(resp)
+    }
+
+    /// Returns an iterator that pages through a collection endpoint,
+    /// `page_size` items at a time, following Asana's `next_page` cursor
+    /// until it's exhausted.
+    pub fn get_paginated<T: DeserializeOwned, S: AsRef<str>>(&mut self, url: S, page_size: usize) -> Paginator<'_, T> {
+        let separator = if url.as_ref().contains('?') { "&" } else { "?" };
+        let first_url = format!("{}{}limit={}", url.as_ref(), separator, page_size);
+
+        Paginator::new(self, first_url)
+    }
+
+    /// Creates a resource by POSTing `body`, wrapped in the `{ "data": ... }`
+    /// envelope Asana expects, to the current url.
+    pub fn post<B: Serialize>(&mut self, body: &B) -> Result<schema::Response, AsanaError> {
+        self.refresh_if_needed()?;
+
+        let resp = self.client
+            .post(&self.url)
+            .bearer_auth(self.token())
+            .json(&Envelope { data: body })
+            .send()?;
+
+        self.finish(resp)
+    }
+
+    /// Updates a resource by PUTting `body`, wrapped in the `{ "data": ... }`
+    /// envelope Asana expects, to the current url.
+    pub fn put<B: Serialize>(&mut self, body: &B) -> Result<schema::Response, AsanaError> {
+        self.refresh_if_needed()?;
+
+        let resp = self.client
+            .put(&self.url)
+            .bearer_auth(self.token())
+            .json(&Envelope { data: body })
+            .send()?;
+
+        self.finish(resp)
+    }
 
+    /// Deletes the resource at the current url.
+    pub fn delete(&mut self) -> Result<schema::Response, AsanaError> {
+        self.refresh_if_needed()?;
+
+        let resp = self.client
+            .delete(&self.url)
+            .bearer_auth(self.token())
+            .send()?;
+
+        self.finish(resp)
+    }
+
+    /// Resets `self.url` and turns a raw HTTP response into a
+    /// `schema::Response`, or the appropriate `AsanaError` if Asana
+    /// rejected or rate limited the request.
+    fn finish(&mut self, resp: HttpResponse) -> Result<schema::Response, AsanaError> {
         // Reset the url
-        self.url = format!("{}", BASE_URL);
-        return Ok(resp);
+        self.url = base_url();
+
+        let status = resp.status().as_u16();
+        let retry_after = resp.headers()
+            .get("Retry-After")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
+
+        let text = resp.text()?;
+        parse_response(status, retry_after, &text)
     }
 }
 
@@ -69,6 +238,57 @@ mod tests {
         assert_eq!(api.token(), "my token");
     }
 
+    #[test]
+    fn parse_response_success() {
+        let text = r#"{ "data": { "gid": "1", "resource_type": "user", "name": "Greg" } }"#;
+        let resp = parse_response(200, None, text).expect("should parse a 2xx response");
+        assert!(resp.errors.is_empty());
+    }
+
+    #[test]
+    fn parse_response_api_error() {
+        let text = r#"{ "errors": [{ "help": null, "message": "Missing input", "phrase": null }] }"#;
+        let err = parse_response(400, None, text).unwrap_err();
+        match err {
+            AsanaError::Api { status, errors } => {
+                assert_eq!(status, 400);
+                assert_eq!(errors[0].message.as_deref(), Some("Missing input"));
+            }
+            other => panic!("expected AsanaError::Api, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_response_rate_limited() {
+        let err = parse_response(429, Some(30), "").unwrap_err();
+        match err {
+            AsanaError::RateLimited { retry_after } => assert_eq!(retry_after, Duration::from_secs(30)),
+            other => panic!("expected AsanaError::RateLimited, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn envelope_wraps_body_under_data() {
+        #[derive(Serialize)]
+        struct Body {
+            name: String,
+        }
+
+        let body = Body { name: String::from("Greg") };
+        let json = serde_json::to_value(&Envelope { data: &body }).unwrap();
+        assert_eq!(json["data"]["name"], "Greg");
+        assert_eq!(json.as_object().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn parse_response_rate_limited_without_retry_after() {
+        let err = parse_response(429, None, "").unwrap_err();
+        match err {
+            AsanaError::RateLimited { retry_after } => assert_eq!(retry_after, Duration::from_secs(0)),
+            other => panic!("expected AsanaError::RateLimited, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_get_me() {
         let mut asana = API::from_token(get_pat());
@@ -0,0 +1,189 @@
+//! OAuth2 authorization-code flow
+//!
+//! https://developers.asana.com/docs/oauth
+
+use std::time::{Duration, Instant};
+
+use reqwest::blocking::Client;
+use serde::Deserialize;
+
+use crate::{schema, AsanaError};
+
+const AUTHORIZE_URL: &'static str = "https://app.asana.com/-/oauth_authorize";
+const TOKEN_URL: &'static str = "https://app.asana.com/-/oauth_token";
+
+/// An access token returned by the `/-/oauth_token` endpoint, either from
+/// an initial code exchange or a refresh.
+#[derive(Deserialize, Debug, Clone)]
+pub struct AccessToken {
+    pub token_type: String,
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    pub expires_in: u64,
+    #[serde(skip, default = "Instant::now")]
+    received_at: Instant,
+}
+
+impl AccessToken {
+    /// Returns true if this token will expire within `margin` from now.
+    pub fn is_expiring(&self, margin: Duration) -> bool {
+        let expires_at = self.received_at + Duration::from_secs(self.expires_in);
+        Instant::now() + margin >= expires_at
+    }
+}
+
+/// Holds the credentials for Asana's OAuth2 authorization-code flow.
+///
+/// Use `authorize_url()` to send the user to Asana's consent screen, then
+/// `exchange_code()` with the code Asana redirects back with to get an
+/// `AccessToken`. Once a token is close to expiring, `refresh()` exchanges
+/// its refresh token for a new one.
+pub struct OAuthClient {
+    client: Client,
+    client_id: String,
+    client_secret: String,
+    redirect_uri: String,
+}
+
+impl OAuthClient {
+    /// Creates a new `OAuthClient` from the app's client ID, client secret,
+    /// and redirect URI, as registered with Asana.
+    pub fn new<S: AsRef<str>>(client_id: S, client_secret: S, redirect_uri: S) -> Self {
+        OAuthClient {
+            client: Client::new(),
+            client_id: String::from(client_id.as_ref()),
+            client_secret: String::from(client_secret.as_ref()),
+            redirect_uri: String::from(redirect_uri.as_ref()),
+        }
+    }
+
+    /// Builds the URL to send the user to for authorization, embedding the
+    /// given `state` so the redirect back can be matched to this request.
+    pub fn authorize_url<S: AsRef<str>>(&self, state: S) -> String {
+        let mut url = url::Url::parse(AUTHORIZE_URL).expect("AUTHORIZE_URL is a valid url");
+        url.query_pairs_mut()
+            .append_pair("client_id", &self.client_id)
+            .append_pair("redirect_uri", &self.redirect_uri)
+            .append_pair("response_type", "code")
+            .append_pair("state", state.as_ref());
+
+        url.to_string()
+    }
+
+    /// Exchanges an authorization `code` (from the redirect back from
+    /// `authorize_url()`) for an `AccessToken`.
+    pub fn exchange_code<S: AsRef<str>>(&self, code: S) -> Result<AccessToken, AsanaError> {
+        self.request_token(&[
+            ("grant_type", "authorization_code"),
+            ("client_id", &self.client_id),
+            ("client_secret", &self.client_secret),
+            ("redirect_uri", &self.redirect_uri),
+            ("code", code.as_ref()),
+        ])
+    }
+
+    /// Exchanges a `refresh_token` for a new `AccessToken`.
+    pub fn refresh<S: AsRef<str>>(&self, refresh_token: S) -> Result<AccessToken, AsanaError> {
+        self.request_token(&[
+            ("grant_type", "refresh_token"),
+            ("client_id", &self.client_id),
+            ("client_secret", &self.client_secret),
+            ("redirect_uri", &self.redirect_uri),
+            ("refresh_token", refresh_token.as_ref()),
+        ])
+    }
+
+    fn request_token(&self, params: &[(&str, &str)]) -> Result<AccessToken, AsanaError> {
+        let resp = self.client
+            .post(TOKEN_URL)
+            .form(params)
+            .send()?;
+
+        let status = resp.status().as_u16();
+        let text = resp.text()?;
+
+        parse_token_response(status, &text)
+    }
+}
+
+/// Turns a `/-/oauth_token` response status and body into an `AccessToken`,
+/// or `AsanaError::Api` if Asana rejected the exchange. Split out from
+/// `request_token` so it can be unit tested without a network call,
+/// mirroring `parse_response` in `api::mod`.
+fn parse_token_response(status: u16, text: &str) -> Result<AccessToken, AsanaError> {
+    if !(200..300).contains(&status) {
+        let errors = serde_json::from_str::<schema::Errors>(text)?;
+        return Err(AsanaError::Api { status, errors: errors.errors });
+    }
+
+    Ok(serde_json::from_str::<AccessToken>(text)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_expiring_false_before_margin() {
+        let token = AccessToken {
+            token_type: String::from("bearer"),
+            access_token: String::from("abc"),
+            refresh_token: None,
+            expires_in: 60,
+            received_at: Instant::now(),
+        };
+
+        assert!(!token.is_expiring(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn is_expiring_true_once_inside_margin() {
+        let token = AccessToken {
+            token_type: String::from("bearer"),
+            access_token: String::from("abc"),
+            refresh_token: None,
+            expires_in: 0,
+            received_at: Instant::now() - Duration::from_millis(10),
+        };
+
+        assert!(token.is_expiring(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn authorize_url_percent_encodes_params() {
+        let oauth = OAuthClient::new(
+            "my id",
+            "my secret",
+            "https://example.com/callback?a=1&b=2",
+        );
+
+        let url = oauth.authorize_url("some state/with?odd&chars");
+        let parsed = url::Url::parse(&url).unwrap();
+        let pairs: std::collections::HashMap<_, _> = parsed.query_pairs().into_owned().collect();
+
+        assert_eq!(pairs.get("client_id").unwrap(), "my id");
+        assert_eq!(pairs.get("redirect_uri").unwrap(), "https://example.com/callback?a=1&b=2");
+        assert_eq!(pairs.get("response_type").unwrap(), "code");
+        assert_eq!(pairs.get("state").unwrap(), "some state/with?odd&chars");
+    }
+
+    #[test]
+    fn parse_token_response_success() {
+        let text = r#"{ "token_type": "bearer", "access_token": "abc", "refresh_token": "def", "expires_in": 3600 }"#;
+        let token = parse_token_response(200, text).expect("should parse a 2xx response");
+        assert_eq!(token.access_token, "abc");
+    }
+
+    #[test]
+    fn parse_token_response_api_error() {
+        let text = r#"{ "errors": [{ "help": null, "message": "invalid_grant", "phrase": null }] }"#;
+        let err = parse_token_response(400, text).unwrap_err();
+        match err {
+            AsanaError::Api { status, errors } => {
+                assert_eq!(status, 400);
+                assert_eq!(errors[0].message.as_deref(), Some("invalid_grant"));
+            }
+            other => panic!("expected AsanaError::Api, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Iterating over paginated collection endpoints
+//!
+//! https://developers.asana.com/docs/pagination
+
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+
+use crate::api::API;
+use crate::AsanaError;
+
+/// A streaming iterator over a paginated Asana collection endpoint.
+///
+/// Each call to `next()` returns one page as a `Vec<T>`, issuing the
+/// follow-up request with the `offset` Asana handed back until there's no
+/// `next_page` left. Created by `API::get_paginated`.
+pub struct Paginator<'a, T> {
+    api: &'a mut API,
+    next_url: Option<String>,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T> Paginator<'a, T> {
+    pub(crate) fn new(api: &'a mut API, first_url: String) -> Self {
+        Paginator {
+            api,
+            next_url: Some(first_url),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: DeserializeOwned> Iterator for Paginator<'a, T> {
+    type Item = Result<Vec<T>, AsanaError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let url = self.next_url.take()?;
+
+        self.api.request(url);
+        match self.api.get() {
+            Ok(resp) => {
+                self.next_url = resp.next_page.as_ref().map(|next_page| next_page.path.clone());
+                match serde_json::from_value::<Vec<T>>(resp.data.clone()) {
+                    Ok(items) => Some(Ok(items)),
+                    Err(e) => Some(Err(AsanaError::Deserialize(e))),
+                }
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::API;
+    use crate::get_pat;
+    use crate::schema;
+
+    #[test]
+    fn paginator_follows_cursor_until_exhausted() {
+        let mut asana = API::from_token(get_pat());
+        let mut pages = asana.get_paginated::<schema::UserCompact, _>("/users", 1);
+
+        let first = pages.next().expect("expected at least one page").expect("page request failed");
+        assert_eq!(first.len(), 1);
+
+        // A second page means Asana handed back a `next_page` cursor and the
+        // paginator followed it.
+        let second = pages.next();
+        assert!(second.is_some());
+    }
+}
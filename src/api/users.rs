@@ -77,10 +77,14 @@ mod tests {
     #[test]
     fn test_get_user_invalid_gid() {
         let mut asana = api();
-        let resp = asana.request( user("something not valid") ).get().unwrap();
-        assert!(resp.errors().is_some());
-        let errors = resp.errors().unwrap();
-        assert_eq!(errors.len(), 1);
+        let err = asana.request( user("something not valid") ).get().unwrap_err();
+        match err {
+            crate::AsanaError::Api { status, errors } => {
+                assert!(status >= 400);
+                assert_eq!(errors.len(), 1);
+            }
+            other => panic!("expected AsanaError::Api, got {:?}", other),
+        }
     }
 
     #[test]
@@ -0,0 +1,63 @@
+//! Errors returned by the `API`
+//!
+//! https://developers.asana.com/docs/errors
+
+use std::fmt;
+use std::time::Duration;
+
+use crate::schema;
+
+/// A typed error from an `API` request.
+///
+/// This lets callers `match` on what went wrong instead of working with a
+/// stringly-typed `Box<dyn std::error::Error>`: whether the transport
+/// itself failed, the response body wasn't valid JSON, Asana returned one
+/// or more API-level errors, or the request was rate limited.
+#[derive(Debug)]
+pub enum AsanaError {
+    /// The request failed before Asana could respond (DNS, TLS, timeout, ...).
+    Http(reqwest::Error),
+    /// The response body couldn't be deserialized.
+    Deserialize(serde_json::Error),
+    /// Asana responded with a non-2xx status and one or more errors.
+    Api { status: u16, errors: Vec<schema::Error> },
+    /// Asana responded with HTTP 429; `retry_after` is parsed from the
+    /// `Retry-After` header, if present.
+    RateLimited { retry_after: Duration },
+}
+
+impl fmt::Display for AsanaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AsanaError::Http(e) => write!(f, "HTTP error: {}", e),
+            AsanaError::Deserialize(e) => write!(f, "failed to deserialize response: {}", e),
+            AsanaError::Api { status, errors } => {
+                write!(f, "Asana API error ({}): ", status)?;
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{}", error.message.as_deref().unwrap_or("unknown error"))?;
+                }
+                Ok(())
+            }
+            AsanaError::RateLimited { retry_after } => {
+                write!(f, "rate limited, retry after {:?}", retry_after)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AsanaError {}
+
+impl From<reqwest::Error> for AsanaError {
+    fn from(e: reqwest::Error) -> Self {
+        AsanaError::Http(e)
+    }
+}
+
+impl From<serde_json::Error> for AsanaError {
+    fn from(e: serde_json::Error) -> Self {
+        AsanaError::Deserialize(e)
+    }
+}
@@ -6,14 +6,22 @@ extern crate url;
 
 
 pub mod api;
+pub mod error;
 pub mod schema;
 
 pub use schema::Response;
 pub use api::API;
+pub use error::AsanaError;
 
 pub const BASE_URL: &'static str = "https://app.asana.com";
 pub const BASE_API: &'static str = "/api/1.0";
 
+/// The base url every request is built against: `BASE_URL` + `BASE_API`,
+/// e.g. `https://app.asana.com/api/1.0`.
+pub(crate) fn base_url() -> String {
+    format!("{}{}", BASE_URL, BASE_API)
+}
+
 // Dev helper
 // This gets the personal access token from .token in the crate root
 #[cfg(test)]
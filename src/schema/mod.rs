@@ -1,7 +1,7 @@
 #![allow(unused)]
 
 pub mod response;
-pub use response::Response;
+pub use response::{NextPage, Response};
 
 /// https://developers.asana.com/docs/schemas
 use serde::{Deserialize, de::DeserializeOwned};
@@ -67,6 +67,107 @@ pub struct UserCompact {
     pub name: String,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct Task {
+    pub gid: String,
+    pub resource_type: String,
+    pub name: String,
+    pub notes: Option<String>,
+    pub completed: bool,
+    pub assignee: Option<UserCompact>,
+    pub workspace: Option<Workspace>,
+    pub projects: Option<Vec<AsanaNamedResource>>,
+    pub due_on: Option<String>,
+    pub due_at: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Project {
+    pub gid: String,
+    pub resource_type: String,
+    pub name: String,
+    pub notes: Option<String>,
+    pub archived: Option<bool>,
+    pub color: Option<String>,
+    pub workspace: Option<Workspace>,
+    pub team: Option<AsanaNamedResource>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Team {
+    pub gid: String,
+    pub resource_type: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub organization: Option<Workspace>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Section {
+    pub gid: String,
+    pub resource_type: String,
+    pub name: String,
+    pub project: Option<AsanaNamedResource>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Tag {
+    pub gid: String,
+    pub resource_type: String,
+    pub name: String,
+    pub notes: Option<String>,
+    pub color: Option<String>,
+}
+
+/// A resource Asana tags with a `resource_type` in its JSON payloads. Lets
+/// `Response::checked_value` verify a payload is actually the type being
+/// deserialized into, instead of silently parsing whatever fields happen
+/// to match.
+pub trait Resource: DeserializeOwned {
+    const RESOURCE_TYPE: &'static str;
+    fn gid(&self) -> &str;
+}
+
+impl Resource for User {
+    const RESOURCE_TYPE: &'static str = "user";
+    fn gid(&self) -> &str { &self.gid }
+}
+
+impl Resource for UserCompact {
+    const RESOURCE_TYPE: &'static str = "user";
+    fn gid(&self) -> &str { &self.gid }
+}
+
+impl Resource for Workspace {
+    const RESOURCE_TYPE: &'static str = "workspace";
+    fn gid(&self) -> &str { &self.gid }
+}
+
+impl Resource for Task {
+    const RESOURCE_TYPE: &'static str = "task";
+    fn gid(&self) -> &str { &self.gid }
+}
+
+impl Resource for Project {
+    const RESOURCE_TYPE: &'static str = "project";
+    fn gid(&self) -> &str { &self.gid }
+}
+
+impl Resource for Team {
+    const RESOURCE_TYPE: &'static str = "team";
+    fn gid(&self) -> &str { &self.gid }
+}
+
+impl Resource for Section {
+    const RESOURCE_TYPE: &'static str = "section";
+    fn gid(&self) -> &str { &self.gid }
+}
+
+impl Resource for Tag {
+    const RESOURCE_TYPE: &'static str = "tag";
+    fn gid(&self) -> &str { &self.gid }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,4 +264,85 @@ mod tests {
         assert!(errors.errors[0].message.is_some());
         assert!(errors.errors[0].phrase.is_some());
     }
+
+    #[test]
+    fn test_task() {
+        let raw = r#"{
+            "gid": "12345",
+            "resource_type": "task",
+            "name": "Bug Task",
+            "notes": "This bug needs to be fixed",
+            "completed": false,
+            "assignee": {
+                "gid": "12345",
+                "resource_type": "user",
+                "name": "Greg Sanchez"
+            },
+            "due_on": "2024-01-01",
+            "due_at": null
+        }"#;
+
+        let task: Task = serde_json::from_str(raw).unwrap();
+        assert_eq!(task.name, "Bug Task");
+        assert!(!task.completed);
+        assert_eq!(task.assignee.unwrap().name, "Greg Sanchez");
+        assert_eq!(Task::RESOURCE_TYPE, "task");
+    }
+
+    #[test]
+    fn test_project() {
+        let raw = r#"{
+            "gid": "12345",
+            "resource_type": "project",
+            "name": "Stuff to buy",
+            "archived": false,
+            "color": "light-green"
+        }"#;
+
+        let project: Project = serde_json::from_str(raw).unwrap();
+        assert_eq!(project.name, "Stuff to buy");
+        assert!(!project.archived.unwrap());
+        assert_eq!(Project::RESOURCE_TYPE, "project");
+    }
+
+    #[test]
+    fn test_team() {
+        let raw = r#"{
+            "gid": "12345",
+            "resource_type": "team",
+            "name": "Marketing",
+            "description": "Marketing team"
+        }"#;
+
+        let team: Team = serde_json::from_str(raw).unwrap();
+        assert_eq!(team.name, "Marketing");
+        assert_eq!(Team::RESOURCE_TYPE, "team");
+    }
+
+    #[test]
+    fn test_section() {
+        let raw = r#"{
+            "gid": "12345",
+            "resource_type": "section",
+            "name": "Next Actions"
+        }"#;
+
+        let section: Section = serde_json::from_str(raw).unwrap();
+        assert_eq!(section.name, "Next Actions");
+        assert_eq!(Section::RESOURCE_TYPE, "section");
+    }
+
+    #[test]
+    fn test_tag() {
+        let raw = r#"{
+            "gid": "12345",
+            "resource_type": "tag",
+            "name": "Urgent",
+            "color": "dark-red"
+        }"#;
+
+        let tag: Tag = serde_json::from_str(raw).unwrap();
+        assert_eq!(tag.name, "Urgent");
+        assert_eq!(Tag::RESOURCE_TYPE, "tag");
+    }
 }
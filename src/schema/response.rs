@@ -11,12 +11,23 @@ use crate::schema;
 /// You can use `value()` or `values()` to get the data returned by Asana, serialized into one
 /// of the structs in the [`schema`](crate::schema) module. You can use `errors()` to return a
 /// vector of `schema::Error`s.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct Response {
     #[serde(default)]
     pub data: serde_json::Value,
     #[serde(default)]
     pub errors: Vec<schema::Error>,
+    #[serde(default)]
+    pub next_page: Option<NextPage>,
+}
+
+/// The pagination cursor Asana includes on collection endpoints once
+/// there are more results than fit in a single page.
+#[derive(Deserialize, Debug, Clone)]
+pub struct NextPage {
+    pub offset: String,
+    pub path: String,
+    pub uri: String,
 }
 
 impl Response {
@@ -58,6 +69,37 @@ impl Response {
         serde_json::from_value::<T>(self.data.clone()).ok()
     }
 
+    /// Same as `value()`, but checks the payload's `resource_type` against
+    /// `T::RESOURCE_TYPE` first. Returns `None` if they don't match, instead
+    /// of deserializing a payload into the wrong resource just because the
+    /// fields happen to line up.
+    ///
+    /// ```rust
+    /// use rust_asana::Response;
+    /// use rust_asana::schema::{Task, UserCompact};
+    ///
+    /// let payload = r#"{
+    ///     "data": {
+    ///         "gid": "12345",
+    ///         "resource_type": "user",
+    ///         "name": "Greg Sanchez"
+    ///     }
+    /// }"#;
+    ///
+    /// let resp = serde_json::from_str::<Response>(&payload).unwrap();
+    /// assert!(resp.checked_value::<UserCompact>().is_some());
+    /// assert!(resp.checked_value::<Task>().is_none());
+    /// ```
+    pub fn checked_value<T: schema::Resource>(&self) -> Option<T> {
+        let resource_type = self.data.get("resource_type")?.as_str()?;
+
+        if resource_type != T::RESOURCE_TYPE {
+            return None;
+        }
+
+        self.value::<T>()
+    }
+
     /// Same as `values()`, but returns the Asana errors (always a vector).
     pub fn errors(&self) -> Option<Vec<schema::Error>> {
         if self.errors.len() > 0 {
@@ -256,6 +298,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_checked_value_matching_resource_type() {
+        let payload = test_value_resp();
+        let resp = serde_json::from_str::<Response>(payload).unwrap();
+        let user = resp.checked_value::<schema::UserCompact>();
+        assert!(user.is_some());
+        assert_eq!(user.unwrap().name, "Greg Sanchez");
+    }
+
+    #[test]
+    fn test_checked_value_mismatched_resource_type() {
+        let payload = test_value_resp();
+        let resp = serde_json::from_str::<Response>(payload).unwrap();
+        assert!(resp.checked_value::<schema::Task>().is_none());
+    }
+
     #[test]
     fn test_empty_return_vector() {
         let payload = r#"{ "data": [] }"#;
@@ -264,4 +322,28 @@ mod tests {
         assert!(items.is_some());
         assert_eq!(items.unwrap().len(), 0);
     }
+
+    #[test]
+    fn test_deserialize_next_page() {
+        let payload = r#"{
+            "data": [],
+            "next_page": {
+                "offset": "eyJ0eXAiOiJKV1Qi",
+                "path": "/tasks?offset=eyJ0eXAiOiJKV1Qi",
+                "uri": "https://app.asana.com/api/1.0/tasks?offset=eyJ0eXAiOiJKV1Qi"
+            }
+        }"#;
+
+        let resp = serde_json::from_str::<Response>(payload).unwrap();
+        let next_page = resp.next_page.unwrap();
+        assert_eq!(next_page.offset, "eyJ0eXAiOiJKV1Qi");
+        assert_eq!(next_page.path, "/tasks?offset=eyJ0eXAiOiJKV1Qi");
+    }
+
+    #[test]
+    fn test_next_page_defaults_to_none() {
+        let payload = test_value_resp();
+        let resp = serde_json::from_str::<Response>(payload).unwrap();
+        assert!(resp.next_page.is_none());
+    }
 }